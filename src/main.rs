@@ -1,11 +1,15 @@
 use chrono::Duration;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::fmt::Debug;
+use std::fs;
 use std::process::ExitCode;
-use sysinfo::{DiskExt, NetworkExt, System };
+use sysinfo::{CpuExt, DiskExt, NetworkExt, System };
 use sysinfo::SystemExt;
 use sysinfo::ComponentExt;
 
+const CONFIG_FILE: &str = "skematicss.toml";
+
 const LOGO_HEIGHT: usize = 9;
 const LOGO_WIDTH: usize = 32;
 const LOGO: [&str; LOGO_HEIGHT] = [
@@ -20,67 +24,300 @@ const LOGO: [&str; LOGO_HEIGHT] = [
     "                               ",
 ];
 
+#[derive(Deserialize, Default)]
+struct FilterConfig {
+    #[serde(default)]
+    is_list_ignored: bool,
+    #[serde(default)]
+    list: Vec<String>,
+    #[serde(default)]
+    regex: bool,
+    #[serde(default)]
+    case_sensitive: bool,
+    #[serde(default)]
+    whole_word: bool,
+}
+
+#[derive(Deserialize, Default)]
+struct Config {
+    disks: Option<FilterConfig>,
+    temperature: Option<FilterConfig>,
+    network: Option<FilterConfig>,
+}
+
+fn load_config() -> Config {
+    let Some(contents) = fs::read_to_string(CONFIG_FILE).ok() else {
+        return Config::default();
+    };
+    match toml::from_str(&contents) {
+        Ok(config) => config,
+        Err(err) => {
+            eprintln!("warning: failed to parse {CONFIG_FILE}: {err}");
+            Config::default()
+        }
+    }
+}
+
+enum CompiledPattern {
+    Regex(Regex),
+    Literal(String),
+}
+
+struct CompiledFilter {
+    is_list_ignored: bool,
+    patterns: Vec<CompiledPattern>,
+    case_sensitive: bool,
+    whole_word: bool,
+}
+
+fn compile_filter(cfg: &FilterConfig) -> CompiledFilter {
+    let patterns = cfg
+        .list
+        .iter()
+        .map(|pattern| {
+            if cfg.regex {
+                let source = if cfg.whole_word {
+                    format!(r"\b(?:{})\b", pattern)
+                } else {
+                    pattern.clone()
+                };
+                let source = if cfg.case_sensitive {
+                    source
+                } else {
+                    format!("(?i){}", source)
+                };
+                CompiledPattern::Regex(Regex::new(&source).unwrap_or_else(|_| {
+                    panic!("invalid regex pattern in {}: {}", CONFIG_FILE, pattern)
+                }))
+            } else if cfg.case_sensitive {
+                CompiledPattern::Literal(pattern.clone())
+            } else {
+                CompiledPattern::Literal(pattern.to_lowercase())
+            }
+        })
+        .collect();
+    CompiledFilter {
+        is_list_ignored: cfg.is_list_ignored,
+        patterns,
+        case_sensitive: cfg.case_sensitive,
+        whole_word: cfg.whole_word,
+    }
+}
+
+fn filter_matches_any(filter: &CompiledFilter, value: &str) -> bool {
+    filter.patterns.iter().any(|pattern| match pattern {
+        CompiledPattern::Regex(re) => re.is_match(value),
+        CompiledPattern::Literal(literal) => {
+            let haystack = if filter.case_sensitive {
+                value.to_string()
+            } else {
+                value.to_lowercase()
+            };
+            if filter.whole_word {
+                haystack.split_whitespace().any(|token| token == literal)
+            } else {
+                haystack.contains(literal.as_str())
+            }
+        }
+    })
+}
+
+/// Returns `true` if `value` should be kept under `filter` (or if `filter` is `None`).
+fn passes_filter(filter: Option<&CompiledFilter>, value: &str) -> bool {
+    match filter {
+        None => true,
+        Some(filter) => {
+            let matched = filter_matches_any(filter, value);
+            if filter.is_list_ignored {
+                !matched
+            } else {
+                matched
+            }
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
 struct DiskInfo{
     mount_point: String,
     total_space_gb: u64,
     used_space_gb: u64,
+    total_space_bytes: u64,
+    used_space_bytes: u64,
     filesystem: String,
 }
 
-impl Debug for DiskInfo{
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result{
-        f.debug_struct("DiskInfo")
-            .field("mount_point", &self.mount_point)
-            .field("total_space_gb", &self.total_space_gb)
-            .field("used_space_gb", &self.used_space_gb)
-            .field("filesystem", &self.filesystem)
-            .finish()
-    }
-}
-
+#[derive(Debug, Serialize)]
 struct TemperatureInfo{
     label: String,
     temperature_celsius: f32,
 }
 
-impl Debug for TemperatureInfo{
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result{
-        f.debug_struct("TemperatureInfo")
-            .field("label", &self.label)
-            .field("temperature_celsius", &self.temperature_celsius)
-            .finish()
-    }
-}
-
+#[derive(Debug, Serialize)]
 struct NetworkInfo{
     interface_name: String,
     bytes_sent: u64,
     bytes_received: u64,
     packets_sent: u64,
     packets_received: u64,
+    sent_rate_bytes_per_sec: f64,
+    received_rate_bytes_per_sec: f64,
+}
+
+/// Tracks the previous per-interface byte counters and the time of the last refresh so
+/// `get_network_info` can turn sysinfo's cumulative counters into throughput rates.
+struct NetworkRateTracker {
+    previous: HashMap<String, (u64, u64)>,
+    rates: HashMap<String, (f64, f64)>,
+    last_refresh: Option<std::time::Instant>,
 }
 
-impl Debug for NetworkInfo{
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result{
-        f.debug_struct("NetworkInfo")
-            .field("interface_name", &self.interface_name)
-            .field("bytes_sent", &self.bytes_sent)
-            .field("bytes_received", &self.bytes_received)
-            .field("packets_sent", &self.packets_sent)
-            .field("packets_received", &self.packets_received)
-            .finish()
+impl NetworkRateTracker {
+    fn new() -> Self {
+        NetworkRateTracker {
+            previous: HashMap::new(),
+            rates: HashMap::new(),
+            last_refresh: None,
+        }
+    }
+}
+
+/// Kernel-level network health counters: system-wide UDP counters, plus device error/drop
+/// counters summed across all non-loopback devices.
+/// Only available on Linux, where `/proc/net/snmp` and `/proc/net/dev` exist.
+#[cfg(target_os = "linux")]
+#[derive(Debug, Serialize, Clone)]
+struct NetworkErrorInfo {
+    udp_in_datagrams: u64,
+    udp_no_ports: u64,
+    udp_in_errors: u64,
+    udp_out_datagrams: u64,
+    udp_rcvbuf_errors: u64,
+    udp_sndbuf_errors: u64,
+    rx_errors: u64,
+    tx_errors: u64,
+    rx_dropped: u64,
+    tx_dropped: u64,
+}
+
+/// Parses the `Udp:` header/value line pair out of `/proc/net/snmp`, returning a map from
+/// column name (e.g. `InDatagrams`) to its value so callers can look fields up by name
+/// rather than relying on a fixed column order.
+#[cfg(target_os = "linux")]
+fn parse_proc_net_snmp_udp(contents: &str) -> Option<HashMap<String, u64>> {
+    let mut lines = contents.lines();
+    loop {
+        let header = lines.next()?;
+        if !header.starts_with("Udp:") {
+            continue;
+        }
+        let values = lines.next()?;
+        if !values.starts_with("Udp:") {
+            return None;
+        }
+        let names = header.split_whitespace().skip(1);
+        let numbers = values.split_whitespace().skip(1);
+        return Some(
+            names
+                .zip(numbers)
+                .filter_map(|(name, number)| {
+                    number.parse::<u64>().ok().map(|n| (name.to_string(), n))
+                })
+                .collect(),
+        );
+    }
+}
+
+/// Sums `rx_errors`/`tx_errors`/`rx_dropped`/`tx_dropped` across every non-loopback
+/// device listed in `/proc/net/dev`.
+#[cfg(target_os = "linux")]
+fn parse_proc_net_dev(contents: &str) -> (u64, u64, u64, u64) {
+    let mut rx_errors = 0u64;
+    let mut tx_errors = 0u64;
+    let mut rx_dropped = 0u64;
+    let mut tx_dropped = 0u64;
+
+    for line in contents.lines() {
+        let Some((interface, rest)) = line.split_once(':') else {
+            continue;
+        };
+        let interface = interface.trim();
+        if interface.is_empty() || interface == "lo" {
+            continue;
+        }
+        let fields: Vec<&str> = rest.split_whitespace().collect();
+        // Receive: bytes packets errs drop fifo frame compressed multicast (indices 0-7)
+        // Transmit: bytes packets errs drop fifo colls carrier compressed (indices 8-15)
+        if fields.len() < 16 {
+            continue;
+        }
+        rx_errors += fields[2].parse::<u64>().unwrap_or(0);
+        rx_dropped += fields[3].parse::<u64>().unwrap_or(0);
+        tx_errors += fields[10].parse::<u64>().unwrap_or(0);
+        tx_dropped += fields[11].parse::<u64>().unwrap_or(0);
     }
+
+    (rx_errors, tx_errors, rx_dropped, tx_dropped)
 }
 
+#[cfg(target_os = "linux")]
+fn get_network_error_info() -> Option<NetworkErrorInfo> {
+    let snmp_contents = fs::read_to_string("/proc/net/snmp").ok()?;
+    let udp_fields = parse_proc_net_snmp_udp(&snmp_contents)?;
+    let dev_contents = fs::read_to_string("/proc/net/dev").ok().unwrap_or_default();
+    let (rx_errors, tx_errors, rx_dropped, tx_dropped) = parse_proc_net_dev(&dev_contents);
+
+    Some(NetworkErrorInfo {
+        udp_in_datagrams: *udp_fields.get("InDatagrams").unwrap_or(&0),
+        udp_no_ports: *udp_fields.get("NoPorts").unwrap_or(&0),
+        udp_in_errors: *udp_fields.get("InErrors").unwrap_or(&0),
+        udp_out_datagrams: *udp_fields.get("OutDatagrams").unwrap_or(&0),
+        udp_rcvbuf_errors: *udp_fields.get("RcvbufErrors").unwrap_or(&0),
+        udp_sndbuf_errors: *udp_fields.get("SndbufErrors").unwrap_or(&0),
+        rx_errors,
+        tx_errors,
+        rx_dropped,
+        tx_dropped,
+    })
+}
+
+#[derive(Debug, Serialize)]
+struct CpuInfo{
+    brand: String,
+    core_count: usize,
+    usage_percent: f32,
+}
+
+#[derive(Debug, Serialize)]
+struct MemoryInfo{
+    total_bytes: u64,
+    used_bytes: u64,
+    swap_total_bytes: u64,
+    swap_used_bytes: u64,
+}
+
+#[derive(Debug, Serialize)]
+struct LoadInfo{
+    one: f64,
+    five: f64,
+    fifteen: f64,
+}
+
+#[derive(Serialize)]
 struct OutputInfo<'a>{
     username: String,
     hostname: String,
     os: String,
     kernel: String,
     uptime: usize,
+    cpu: CpuInfo,
+    memory: MemoryInfo,
+    load: LoadInfo,
     disks: HashMap<String, DiskInfo>,
     temperatures: HashMap<String, TemperatureInfo>,
     networks: HashMap<&'a str, NetworkInfo>,
+    #[cfg(target_os = "linux")]
+    network_errors: Option<NetworkErrorInfo>,
 }
 
 fn get_username() -> String{
@@ -103,16 +340,21 @@ fn get_uptime(sys: &System) -> usize{
     sys.uptime() as usize
 }
 
-fn get_disk_info(sys: &System) -> HashMap<String, DiskInfo> {
+fn get_disk_info(sys: &System, filter: Option<&CompiledFilter>) -> HashMap<String, DiskInfo> {
     let mut disk_info_map = HashMap::new();
     for disk in sys.disks() {
         let mount_point = disk.mount_point().to_string_lossy().into_owned();
+        if !passes_filter(filter, &mount_point) {
+            continue;
+        }
         disk_info_map.insert(
             mount_point.clone(),
             DiskInfo {
                 mount_point: mount_point.clone(),
                 total_space_gb: disk.total_space() / 1024 / 1024 / 1024,
                 used_space_gb: (disk.total_space() - disk.available_space()) / 1024 / 1024 / 1024,
+                total_space_bytes: disk.total_space(),
+                used_space_bytes: disk.total_space() - disk.available_space(),
                 filesystem: String::from_utf8_lossy(disk.file_system()).into_owned(),
             },
         );
@@ -120,10 +362,16 @@ fn get_disk_info(sys: &System) -> HashMap<String, DiskInfo> {
     disk_info_map
 }
 
-fn get_temperature_info(sys: &System) -> HashMap<String, TemperatureInfo> {
+fn get_temperature_info(
+    sys: &System,
+    filter: Option<&CompiledFilter>,
+) -> HashMap<String, TemperatureInfo> {
     let mut temp_info_map = HashMap::new();
     for component in sys.components() {
         let label = component.label().to_string();
+        if !passes_filter(filter, &label) {
+            continue;
+        }
         temp_info_map.insert(
             label.clone(),
             TemperatureInfo {
@@ -135,23 +383,123 @@ fn get_temperature_info(sys: &System) -> HashMap<String, TemperatureInfo> {
     temp_info_map
 }
 
-fn get_network_info<'a>(sys: &'a System) -> HashMap<&'a str, NetworkInfo>{
+/// `is_real_refresh` must be `true` only on ticks where `sys.refresh_networks()` was just
+/// called. On the staggered-refresh ticks in between (see `run_watch_loop`), `sys.networks()`
+/// still returns the same cached byte counters, so rates are held at their last computed
+/// value instead of being recomputed against a stale baseline over the wrong elapsed time.
+fn get_network_info<'a>(
+    sys: &'a System,
+    filter: Option<&CompiledFilter>,
+    rate_tracker: &mut NetworkRateTracker,
+    is_real_refresh: bool,
+) -> HashMap<&'a str, NetworkInfo>{
     let mut network_info_map = HashMap::new();
+    let now = std::time::Instant::now();
+    let elapsed_secs = rate_tracker
+        .last_refresh
+        .map(|last| now.duration_since(last).as_secs_f64())
+        .filter(|secs| *secs > 0.0);
+
     for (interface_name, network) in sys.networks() {
+        if !passes_filter(filter, interface_name) {
+            continue;
+        }
+        let bytes_sent = network.total_transmitted();
+        let bytes_received = network.total_received();
+
+        let (sent_rate_bytes_per_sec, received_rate_bytes_per_sec) = if is_real_refresh {
+            // Guard against counter resets (e.g. an interface restart) by clamping to zero
+            // instead of producing a huge rate from wrapping subtraction.
+            let rates = match (elapsed_secs, rate_tracker.previous.get(interface_name)) {
+                (Some(secs), Some(&(prev_sent, prev_received))) => {
+                    let sent_delta = bytes_sent.saturating_sub(prev_sent);
+                    let received_delta = bytes_received.saturating_sub(prev_received);
+                    (sent_delta as f64 / secs, received_delta as f64 / secs)
+                }
+                _ => (0.0, 0.0),
+            };
+            rate_tracker
+                .previous
+                .insert(interface_name.to_string(), (bytes_sent, bytes_received));
+            rate_tracker
+                .rates
+                .insert(interface_name.to_string(), rates);
+            rates
+        } else {
+            rate_tracker
+                .rates
+                .get(interface_name)
+                .copied()
+                .unwrap_or((0.0, 0.0))
+        };
+
         network_info_map.insert(
             interface_name.as_str(),
             NetworkInfo {
                 interface_name: interface_name.to_string(),
-                bytes_sent: network.total_transmitted(),
-                bytes_received: network.total_received(),
+                bytes_sent,
+                bytes_received,
                 packets_sent: network.packets_transmitted(),
                 packets_received: network.packets_received(),
+                sent_rate_bytes_per_sec,
+                received_rate_bytes_per_sec,
             },
         );
     }
+    if is_real_refresh {
+        rate_tracker.last_refresh = Some(now);
+    }
     network_info_map
 }
 
+fn get_cpu_info(sys: &System) -> CpuInfo {
+    let global_cpu = sys.global_cpu_info();
+    CpuInfo {
+        brand: global_cpu.brand().to_string(),
+        core_count: sys.cpus().len(),
+        usage_percent: global_cpu.cpu_usage(),
+    }
+}
+
+fn get_memory_info(sys: &System) -> MemoryInfo {
+    MemoryInfo {
+        total_bytes: sys.total_memory(),
+        used_bytes: sys.used_memory(),
+        swap_total_bytes: sys.total_swap(),
+        swap_used_bytes: sys.used_swap(),
+    }
+}
+
+fn get_load_info(sys: &System) -> LoadInfo {
+    let load_average = sys.load_average();
+    LoadInfo {
+        one: load_average.one,
+        five: load_average.five,
+        fifteen: load_average.fifteen,
+    }
+}
+
+fn convert_bytes_to_human_string(bytes: u64) -> String{
+    const GIB: f64 = 1024.0 * 1024.0 * 1024.0;
+    const MIB: f64 = 1024.0 * 1024.0;
+    let bytes = bytes as f64;
+    if bytes >= GIB {
+        format!("{:.1} GiB", bytes / GIB)
+    } else {
+        format!("{:.1} MiB", bytes / MIB)
+    }
+}
+
+fn format_rate_bytes_per_sec(bytes_per_sec: f64) -> String{
+    const MIB: f64 = 1024.0 * 1024.0;
+    const KIB: f64 = 1024.0;
+    if bytes_per_sec >= MIB {
+        format!("{:.1} MiB/s", bytes_per_sec / MIB)
+    } else {
+        format!("{:.1} KiB/s", bytes_per_sec / KIB)
+    }
+}
+
 fn convert_unix_to_human_string(unix_time: usize) -> String{
     let duration = Duration::seconds(unix_time as i64);
     let days = duration.num_days();
@@ -174,6 +522,24 @@ fn print_all_info(output_info: &OutputInfo) {
         format!("OS:        {}", output_info.os),
         format!("Kernel:    {}", output_info.kernel),
         format!("Uptime:    {}", convert_unix_to_human_string(output_info.uptime)),
+        format!(
+            "CPU:       {} ({} cores) - {:.1}% usage",
+            output_info.cpu.brand, output_info.cpu.core_count, output_info.cpu.usage_percent
+        ),
+        format!(
+            "Memory:    {} used / {} total",
+            convert_bytes_to_human_string(output_info.memory.used_bytes),
+            convert_bytes_to_human_string(output_info.memory.total_bytes)
+        ),
+        format!(
+            "Swap:      {} used / {} total",
+            convert_bytes_to_human_string(output_info.memory.swap_used_bytes),
+            convert_bytes_to_human_string(output_info.memory.swap_total_bytes)
+        ),
+        format!(
+            "Load:      {:.2} {:.2} {:.2} (1m 5m 15m)",
+            output_info.load.one, output_info.load.five, output_info.load.fifteen
+        ),
     ];
     for (mount_point, disk_info) in &output_info.disks {
         output_info_vec.push(format!(
@@ -189,12 +555,22 @@ fn print_all_info(output_info: &OutputInfo) {
     }
     for (interface_name, network_info) in &output_info.networks {
         output_info_vec.push(format!(
-            "Network:   {} - {} MB sent, {} MB recv, {} pkts sent, {} pkts recv",
+            "Network:   {} - {} up, {} down",
             interface_name,
-            network_info.bytes_sent / 1024 / 1024,
-            network_info.bytes_received / 1024 / 1024,
-            network_info.packets_sent,
-            network_info.packets_received
+            format_rate_bytes_per_sec(network_info.sent_rate_bytes_per_sec),
+            format_rate_bytes_per_sec(network_info.received_rate_bytes_per_sec)
+        ));
+    }
+    #[cfg(target_os = "linux")]
+    if let Some(network_errors) = &output_info.network_errors {
+        output_info_vec.push(format!(
+            "Net errors:rx_err={} tx_err={} rx_drop={} tx_drop={} udp_rcvbuf_err={} udp_sndbuf_err={}",
+            network_errors.rx_errors,
+            network_errors.tx_errors,
+            network_errors.rx_dropped,
+            network_errors.tx_dropped,
+            network_errors.udp_rcvbuf_errors,
+            network_errors.udp_sndbuf_errors
         ));
     }
     println!();
@@ -213,26 +589,187 @@ fn print_all_info(output_info: &OutputInfo) {
     println!();
 }
 
+fn print_json(output_info: &OutputInfo) {
+    match serde_json::to_string_pretty(output_info) {
+        Ok(json) => println!("{}", json),
+        Err(err) => eprintln!("failed to serialize output as JSON: {}", err),
+    }
+}
+
+/// The slower subsystems (disks, temperatures, networks) change far less often than
+/// CPU/memory, so in watch mode they're only re-sampled every
+/// `SLOW_REFRESH_INTERVAL_SECS / interval` ticks (rounded down, at least one tick).
+const SLOW_REFRESH_INTERVAL_SECS: u64 = 5;
+
+#[derive(Clone, Copy)]
+enum OutputFormat {
+    Text,
+    Json,
+}
+
+struct CliArgs {
+    watch: bool,
+    interval_secs: u64,
+    format: OutputFormat,
+}
+
+fn parse_cli_args() -> CliArgs {
+    let args: Vec<String> = std::env::args().collect();
+    let watch = args.iter().any(|arg| arg == "--watch");
+    let interval_secs = args
+        .iter()
+        .position(|arg| arg == "--interval")
+        .and_then(|idx| args.get(idx + 1))
+        .and_then(|value| value.parse::<u64>().ok())
+        .unwrap_or(1)
+        .max(1);
+    let format = args
+        .iter()
+        .position(|arg| arg == "--format")
+        .and_then(|idx| args.get(idx + 1))
+        .map(|value| match value.as_str() {
+            "json" => OutputFormat::Json,
+            _ => OutputFormat::Text,
+        })
+        .unwrap_or(OutputFormat::Text);
+    CliArgs { watch, interval_secs, format }
+}
+
+/// `network_is_real_refresh` also gates `/proc/net/snmp`/`/proc/net/dev` reads on Linux,
+/// keyed off the same staggered slow-refresh cadence as `get_network_info`'s rates.
+fn build_output_info<'a>(
+    sys: &'a System,
+    disk_filter: Option<&CompiledFilter>,
+    temperature_filter: Option<&CompiledFilter>,
+    network_filter: Option<&CompiledFilter>,
+    network_rate_tracker: &mut NetworkRateTracker,
+    network_is_real_refresh: bool,
+    #[cfg(target_os = "linux")] network_error_cache: &mut Option<NetworkErrorInfo>,
+) -> OutputInfo<'a> {
+    OutputInfo {
+        username: get_username(),
+        hostname: get_hostname(),
+        os: get_os_name(),
+        kernel: kernel(sys),
+        uptime: get_uptime(sys),
+        cpu: get_cpu_info(sys),
+        memory: get_memory_info(sys),
+        load: get_load_info(sys),
+        disks: get_disk_info(sys, disk_filter),
+        temperatures: get_temperature_info(sys, temperature_filter),
+        networks: get_network_info(sys, network_filter, network_rate_tracker, network_is_real_refresh),
+        #[cfg(target_os = "linux")]
+        network_errors: {
+            if network_is_real_refresh {
+                *network_error_cache = get_network_error_info();
+            }
+            network_error_cache.clone()
+        },
+    }
+}
+
+fn clear_screen() {
+    use std::io::Write;
+    print!("\x1B[2J\x1B[H");
+    let _ = std::io::stdout().flush();
+}
+
+fn run_watch_loop(
+    interval_secs: u64,
+    format: OutputFormat,
+    disk_filter: Option<&CompiledFilter>,
+    temperature_filter: Option<&CompiledFilter>,
+    network_filter: Option<&CompiledFilter>,
+) {
+    let mut sys = System::new_all();
+    sys.refresh_disks();
+    sys.refresh_components();
+    sys.refresh_networks();
+    sys.refresh_cpu();
+    sys.refresh_memory();
+
+    let slow_refresh_ticks = (SLOW_REFRESH_INTERVAL_SECS / interval_secs).max(1);
+    let mut ticks_since_slow_refresh = 0u64;
+    let mut network_rate_tracker = NetworkRateTracker::new();
+    #[cfg(target_os = "linux")]
+    let mut network_error_cache: Option<NetworkErrorInfo> = None;
+
+    loop {
+        sys.refresh_cpu();
+        sys.refresh_memory();
+        let network_is_real_refresh = ticks_since_slow_refresh == 0;
+        if network_is_real_refresh {
+            sys.refresh_disks();
+            sys.refresh_components();
+            sys.refresh_networks();
+        }
+
+        let output_info = build_output_info(
+            &sys,
+            disk_filter,
+            temperature_filter,
+            network_filter,
+            &mut network_rate_tracker,
+            network_is_real_refresh,
+            #[cfg(target_os = "linux")]
+            &mut network_error_cache,
+        );
+        clear_screen();
+        match format {
+            OutputFormat::Json => print_json(&output_info),
+            OutputFormat::Text => print_all_info(&output_info),
+        }
+
+        ticks_since_slow_refresh = (ticks_since_slow_refresh + 1) % slow_refresh_ticks;
+        std::thread::sleep(std::time::Duration::from_secs(interval_secs));
+    }
+}
+
 fn main() -> ExitCode{
     // Removed unsupported sysinfo::IS_SUPPORTED_SYSTEM check
 
+    let cli_args = parse_cli_args();
+    let config = load_config();
+    let disk_filter = config.disks.as_ref().map(compile_filter);
+    let temperature_filter = config.temperature.as_ref().map(compile_filter);
+    let network_filter = config.network.as_ref().map(compile_filter);
+
+    if cli_args.watch {
+        run_watch_loop(
+            cli_args.interval_secs,
+            cli_args.format,
+            disk_filter.as_ref(),
+            temperature_filter.as_ref(),
+            network_filter.as_ref(),
+        );
+        return ExitCode::from(0);
+    }
+
     let mut sys = System::new_all();
     sys.refresh_disks();
     sys.refresh_components();
     sys.refresh_networks();
+    sys.refresh_cpu();
+    sys.refresh_memory();
 
-    let output_info = OutputInfo {
-        username: get_username(),
-        hostname: get_hostname(),
-        os: get_os_name(),
-        kernel: kernel(&sys),
-        uptime: get_uptime(&sys),
-        disks: get_disk_info(&sys),
-        temperatures: get_temperature_info(&sys),
-        networks: get_network_info(&sys),
-    };
+    let mut network_rate_tracker = NetworkRateTracker::new();
+    #[cfg(target_os = "linux")]
+    let mut network_error_cache: Option<NetworkErrorInfo> = None;
+    let output_info = build_output_info(
+        &sys,
+        disk_filter.as_ref(),
+        temperature_filter.as_ref(),
+        network_filter.as_ref(),
+        &mut network_rate_tracker,
+        true,
+        #[cfg(target_os = "linux")]
+        &mut network_error_cache,
+    );
 
-    print_all_info(&output_info);
+    match cli_args.format {
+        OutputFormat::Json => print_json(&output_info),
+        OutputFormat::Text => print_all_info(&output_info),
+    }
 
     ExitCode::from(0)
 }
\ No newline at end of file